@@ -0,0 +1,226 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::rc::Rc;
+
+use serde::Serialize;
+
+use crate::db::JiraDatabase;
+use crate::models::{Epic, Story};
+
+/// An `Epic` with the id it's stored under, so API responses carry it
+/// without making callers cross-reference the board's `HashMap`.
+#[derive(Serialize)]
+struct EpicView<'a> {
+  id: u32,
+  #[serde(flatten)]
+  epic: &'a Epic,
+}
+
+/// A `Story` with the id it's stored under; see `EpicView`.
+#[derive(Serialize)]
+struct StoryView<'a> {
+  id: u32,
+  #[serde(flatten)]
+  story: &'a Story,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+  error: String,
+}
+
+/// A routed HTTP response: status code plus a JSON body.
+struct Response {
+  status: u16,
+  body: String,
+}
+
+impl Response {
+  fn json(status: u16, body: impl Serialize) -> Self {
+    Self { status, body: serde_json::to_string(&body).expect("serializing a response body") }
+  }
+
+  fn not_found(message: impl Into<String>) -> Self {
+    Self::json(404, ErrorBody { error: message.into() })
+  }
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+  match status {
+    200 => "OK",
+    404 => "Not Found",
+    500 => "Internal Server Error",
+    _ => "Unknown",
+  }
+}
+
+/// Routes a single `GET` request to its handler. Read-only by design: the
+/// admin API exists to inspect the board, never to mutate it.
+fn route(db: &JiraDatabase, method: &str, path: &str) -> Response {
+  if method != "GET" {
+    return Response::not_found(format!("unsupported method {}", method));
+  }
+
+  let state = match db.read() {
+    Result::Ok(state) => state,
+    Result::Err(err) => return Response::json(500, ErrorBody { error: err.to_string() }),
+  };
+
+  let segments: Vec<&str> = path.trim_matches('/').split('/').filter(|s| !s.is_empty()).collect();
+
+  match segments.as_slice() {
+    ["epics"] => {
+      let mut epics: Vec<EpicView> = state.epics.iter().map(|(id, epic)| EpicView { id: *id, epic }).collect();
+      epics.sort_by_key(|view| view.id);
+      Response::json(200, epics)
+    }
+    ["epics", id] => match id.parse::<u32>().ok().and_then(|id| state.epics.get(&id).map(|epic| (id, epic))) {
+      Some((id, epic)) => Response::json(200, EpicView { id, epic }),
+      None => Response::not_found(format!("epic {} not found", id)),
+    },
+    ["epics", id, "stories"] => match id.parse::<u32>().ok().and_then(|id| state.epics.get(&id)) {
+      Some(epic) => {
+        let stories: Vec<StoryView> = epic
+          .stories
+          .iter()
+          .filter_map(|story_id| state.stories.get(story_id).map(|story| StoryView { id: *story_id, story }))
+          .collect();
+        Response::json(200, stories)
+      }
+      None => Response::not_found(format!("epic {} not found", id)),
+    },
+    _ => Response::not_found(format!("no route for {}", path)),
+  }
+}
+
+/// Parses the request line of a (very minimal) HTTP/1.1 request, e.g.
+/// `GET /epics/1/stories HTTP/1.1`, and skips past the headers that follow.
+fn read_request_line(stream: &mut TcpStream) -> std::io::Result<Option<(String, String)>> {
+  let mut reader = BufReader::new(stream.try_clone()?);
+
+  let mut request_line = String::new();
+  if reader.read_line(&mut request_line)? == 0 {
+    return Result::Ok(None);
+  }
+
+  let mut parts = request_line.split_whitespace();
+  let method = parts.next().unwrap_or("").to_string();
+  let path = parts.next().unwrap_or("/").to_string();
+
+  let mut header_line = String::new();
+  loop {
+    header_line.clear();
+    if reader.read_line(&mut header_line)? == 0 || header_line.trim().is_empty() {
+      break;
+    }
+  }
+
+  Result::Ok(Some((method, path)))
+}
+
+fn handle_connection(mut stream: TcpStream, db: &JiraDatabase) -> std::io::Result<()> {
+  let Some((method, path)) = read_request_line(&mut stream)? else {
+    return Result::Ok(());
+  };
+
+  let response = route(db, &method, &path);
+  let body = response.body.into_bytes();
+
+  write!(
+    stream,
+    "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+    response.status,
+    reason_phrase(response.status),
+    body.len()
+  )?;
+  stream.write_all(&body)?;
+  stream.flush()
+}
+
+/// Serves the read-only admin API on `addr` (e.g. `127.0.0.1:8080`),
+/// handling one connection at a time. Exposes `GET /epics`,
+/// `GET /epics/{id}` and `GET /epics/{id}/stories` over `db`.
+pub fn serve(addr: &str, db: Rc<JiraDatabase>) -> std::io::Result<()> {
+  let listener = TcpListener::bind(addr)?;
+  println!("my_jira_cli admin API listening on http://{}", addr);
+
+  for stream in listener.incoming() {
+    let stream = stream?;
+    if let Err(err) = handle_connection(stream, &db) {
+      eprintln!("error handling request: {}", err);
+    }
+  }
+
+  Result::Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::db::test_utils::MockDB;
+  use crate::models::{DbState, Status};
+  use std::collections::HashMap;
+
+  fn seeded_db() -> JiraDatabase {
+    let mut epics = HashMap::new();
+    epics.insert(1, Epic { name: "Epic 1".to_string(), description: "first epic".to_string(), status: Status::Open, stories: vec![10] });
+
+    let mut stories = HashMap::new();
+    stories.insert(10, Story { name: "Story 1".to_string(), description: "first story".to_string(), status: Status::Open });
+
+    let state = DbState { schema_version: 1, last_item_id: 10, epics, stories };
+    JiraDatabase::with_backend(Box::new(MockDB::new_with_state(state)))
+  }
+
+  #[test]
+  fn list_epics_returns_all_epics() {
+    let db = seeded_db();
+    let response = route(&db, "GET", "/epics");
+
+    assert_eq!(response.status, 200);
+    assert!(response.body.contains("\"id\":1"));
+    assert!(response.body.contains("Epic 1"));
+  }
+
+  #[test]
+  fn get_epic_by_id_returns_that_epic() {
+    let db = seeded_db();
+    let response = route(&db, "GET", "/epics/1");
+
+    assert_eq!(response.status, 200);
+    assert!(response.body.contains("Epic 1"));
+  }
+
+  #[test]
+  fn get_epic_by_unknown_id_returns_404() {
+    let db = seeded_db();
+    let response = route(&db, "GET", "/epics/999");
+
+    assert_eq!(response.status, 404);
+  }
+
+  #[test]
+  fn get_epic_stories_returns_its_stories() {
+    let db = seeded_db();
+    let response = route(&db, "GET", "/epics/1/stories");
+
+    assert_eq!(response.status, 200);
+    assert!(response.body.contains("Story 1"));
+  }
+
+  #[test]
+  fn get_stories_of_unknown_epic_returns_404() {
+    let db = seeded_db();
+    let response = route(&db, "GET", "/epics/999/stories");
+
+    assert_eq!(response.status, 404);
+  }
+
+  #[test]
+  fn non_get_method_is_rejected() {
+    let db = seeded_db();
+    let response = route(&db, "POST", "/epics");
+
+    assert_eq!(response.status, 404);
+  }
+}
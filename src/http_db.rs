@@ -0,0 +1,257 @@
+use std::cell::RefCell;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::{Database, LoadError, SaveError};
+use crate::models::DbState;
+
+/// The minimal HTTP verbs `HttpDb` needs. Abstracted behind a trait so
+/// tests can swap in a mock transport instead of hitting a real service.
+pub trait HttpClient {
+  fn get(&self, url: &str) -> Result<HttpResponse, String>;
+  fn put(&self, url: &str, body: Vec<u8>) -> Result<HttpResponse, String>;
+}
+
+pub struct HttpResponse {
+  pub status: u16,
+  pub body: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CouchDoc {
+  _id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  _rev: Option<String>,
+  #[serde(flatten)]
+  state: DbState,
+}
+
+#[derive(Deserialize)]
+struct PutAck {
+  rev: String,
+}
+
+/// A `Database` backed by a single document in a CouchDB-style HTTP store.
+/// `read` is a GET of `{base_url}/{doc_id}`; `write` is a PUT carrying the
+/// revision last seen (from `read`, or from the previous `write`'s
+/// acknowledgement), so an edit that raced with someone else's
+/// surfaces as `SaveError::Conflict` (HTTP 409) instead of clobbering it.
+pub struct HttpDb<C: HttpClient> {
+  base_url: String,
+  doc_id: String,
+  client: C,
+  revision: RefCell<Option<String>>,
+}
+
+impl<C: HttpClient> HttpDb<C> {
+  pub fn new(base_url: &str, doc_id: &str, client: C) -> Self {
+    Self {
+      base_url: base_url.trim_end_matches('/').to_string(),
+      doc_id: doc_id.to_string(),
+      client,
+      revision: RefCell::new(None),
+    }
+  }
+
+  fn doc_url(&self) -> String {
+    format!("{}/{}", self.base_url, self.doc_id)
+  }
+}
+
+impl<C: HttpClient> Database for HttpDb<C> {
+  fn read(&self) -> Result<DbState, LoadError> {
+    let url = self.doc_url();
+    let response = self.client.get(&url).map_err(LoadError::Http)?;
+
+    match response.status {
+      200..=299 => {
+        let doc: CouchDoc = serde_json::from_slice(&response.body).map_err(|err| LoadError::Parse(err.to_string()))?;
+        *self.revision.borrow_mut() = doc._rev;
+        Ok(doc.state)
+      }
+      404 => Err(LoadError::Http(format!("document {} not found", self.doc_id))),
+      status => Err(LoadError::Http(format!("unexpected status {} from {}", status, url))),
+    }
+  }
+
+  fn write(&self, state: &DbState) -> Result<(), SaveError> {
+    let url = self.doc_url();
+    let doc = CouchDoc {
+      _id: self.doc_id.clone(),
+      _rev: self.revision.borrow().clone(),
+      state: state.clone(),
+    };
+    let body = serde_json::to_vec(&doc).map_err(|err| SaveError::Serialize(err.to_string()))?;
+    let response = self.client.put(&url, body).map_err(SaveError::Http)?;
+
+    match response.status {
+      200..=299 => {
+        let ack: PutAck = serde_json::from_slice(&response.body)
+          .map_err(|err| SaveError::Http(format!("unexpected response body from {}: {}", url, err)))?;
+        *self.revision.borrow_mut() = Some(ack.rev);
+        Ok(())
+      }
+      409 => Err(SaveError::Conflict),
+      status => Err(SaveError::Http(format!("unexpected status {} from {}", status, url))),
+    }
+  }
+}
+
+/// The production `HttpClient`, backed by a real blocking HTTP call.
+pub struct UreqHttpClient;
+
+impl HttpClient for UreqHttpClient {
+  fn get(&self, url: &str) -> Result<HttpResponse, String> {
+    match ureq::get(url).call() {
+      Ok(response) => Ok(read_response(response)),
+      Err(ureq::Error::Status(status, response)) => Ok(HttpResponse { status, body: read_body(response) }),
+      Err(err) => Err(err.to_string()),
+    }
+  }
+
+  fn put(&self, url: &str, body: Vec<u8>) -> Result<HttpResponse, String> {
+    match ureq::put(url).send_bytes(&body) {
+      Ok(response) => Ok(read_response(response)),
+      Err(ureq::Error::Status(status, response)) => Ok(HttpResponse { status, body: read_body(response) }),
+      Err(err) => Err(err.to_string()),
+    }
+  }
+}
+
+fn read_response(response: ureq::Response) -> HttpResponse {
+  let status = response.status();
+  HttpResponse { status, body: read_body(response) }
+}
+
+fn read_body(response: ureq::Response) -> Vec<u8> {
+  let mut body = Vec::new();
+  let _ = response.into_reader().read_to_end(&mut body);
+  body
+}
+
+pub mod test_utils {
+  use std::cell::RefCell;
+  use std::collections::HashMap;
+
+  use super::{HttpClient, HttpResponse};
+
+  /// A canned HTTP transport: `responses` maps "METHOD url" to the
+  /// response that request should get back, in the order they're queued.
+  /// `put_bodies` records every body a `put` was actually called with, so
+  /// tests can assert on what `HttpDb` sent rather than just whether the
+  /// call succeeded.
+  pub struct MockHttpClient {
+    pub responses: RefCell<HashMap<String, Vec<(u16, Vec<u8>)>>>,
+    pub put_bodies: RefCell<Vec<Vec<u8>>>,
+  }
+
+  impl MockHttpClient {
+    pub fn new() -> Self {
+      Self { responses: RefCell::new(HashMap::new()), put_bodies: RefCell::new(Vec::new()) }
+    }
+
+    pub fn queue(&self, method: &str, url: &str, status: u16, body: &[u8]) {
+      self.responses
+        .borrow_mut()
+        .entry(format!("{} {}", method, url))
+        .or_default()
+        .push((status, body.to_vec()));
+    }
+
+    /// The body of the most recent `put` call, if any.
+    pub fn last_put_body(&self) -> Option<Vec<u8>> {
+      self.put_bodies.borrow().last().cloned()
+    }
+
+    fn take(&self, method: &str, url: &str) -> Result<HttpResponse, String> {
+      let key = format!("{} {}", method, url);
+      let mut responses = self.responses.borrow_mut();
+      let queued = responses.get_mut(&key).ok_or_else(|| format!("no mock response queued for {}", key))?;
+
+      if queued.is_empty() {
+        return Err(format!("mock response queue exhausted for {}", key));
+      }
+
+      let (status, body) = queued.remove(0);
+      Ok(HttpResponse { status, body })
+    }
+  }
+
+  impl HttpClient for MockHttpClient {
+    fn get(&self, url: &str) -> Result<HttpResponse, String> {
+      self.take("GET", url)
+    }
+
+    fn put(&self, url: &str, body: Vec<u8>) -> Result<HttpResponse, String> {
+      self.put_bodies.borrow_mut().push(body);
+      self.take("PUT", url)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::test_utils::MockHttpClient;
+  use super::*;
+  use crate::db::StoreError;
+  use crate::JiraDatabase;
+
+  #[test]
+  fn read_not_found_surfaces_as_load_error() {
+    let client = MockHttpClient::new();
+    client.queue("GET", "http://db.local/board", 404, b"{}");
+
+    let db = HttpDb::new("http://db.local", "board", client);
+    let result = db.read();
+
+    assert!(matches!(result, Err(LoadError::Http(_))));
+  }
+
+  #[test]
+  fn write_conflict_surfaces_as_conflict_error() {
+    let client = MockHttpClient::new();
+    client.queue("PUT", "http://db.local/board", 409, b"{}");
+
+    let db = HttpDb::new("http://db.local", "board", client);
+    let state = DbState {
+      schema_version: 1,
+      last_item_id: 0,
+      epics: std::collections::HashMap::new(),
+      stories: std::collections::HashMap::new(),
+    };
+
+    let result = db.write(&state);
+    assert!(matches!(result, Err(SaveError::Conflict)));
+  }
+
+  #[test]
+  fn read_then_write_carries_the_revision_forward() {
+    let client = MockHttpClient::new();
+    client.queue(
+      "GET",
+      "http://db.local/board",
+      200,
+      br#"{"_id":"board","_rev":"1-abc","schema_version":1,"last_item_id":0,"epics":{},"stories":{}}"#,
+    );
+    client.queue("PUT", "http://db.local/board", 200, br#"{"ok":true,"id":"board","rev":"2-def"}"#);
+
+    let db = HttpDb::new("http://db.local", "board", client);
+    let state = db.read().expect("read should succeed");
+
+    assert!(db.write(&state).is_ok());
+
+    let sent_body = db.client.last_put_body().expect("write should have called put");
+    let sent = String::from_utf8(sent_body).expect("put body should be utf8");
+    assert!(sent.contains(r#""_rev":"1-abc""#), "put body should carry the revision seen by read, got: {sent}");
+  }
+
+  #[test]
+  fn jira_database_with_http_backend_reports_not_found_as_store_error() {
+    let client = MockHttpClient::new();
+    client.queue("GET", "http://db.local/board", 404, b"{}");
+
+    let jira_db = JiraDatabase::with_backend(Box::new(HttpDb::new("http://db.local", "board", client)));
+
+    assert!(matches!(jira_db.read(), Err(StoreError::Load(LoadError::Http(_)))));
+  }
+}
@@ -1,4 +1,6 @@
+mod api;
 mod db;
+mod http_db;
 mod models;
 mod ui;
 mod io;
@@ -7,15 +9,54 @@ mod nav;
 use std::rc::Rc;
 
 use db::JiraDatabase;
+use http_db::{HttpDb, UreqHttpClient};
 use io::get_input;
 use nav::Navigator;
 
 use crate::models::*;
 
+/// Picks the storage backend: a `JIRA_DB_URL` env var selects the HTTP
+/// backend (e.g. `http://localhost:5984/jira`, split into base url + doc
+/// id at the last `/`); otherwise falls back to the local JSON file.
+fn build_database() -> JiraDatabase {
+    match std::env::var("JIRA_DB_URL") {
+        Ok(url) => {
+            let (base_url, doc_id) = url
+                .rsplit_once('/')
+                .unwrap_or((url.as_str(), "jira"));
+            JiraDatabase::with_backend(Box::new(HttpDb::new(base_url, doc_id, UreqHttpClient)))
+        }
+        Err(_) => JiraDatabase::new("./data/database.json"),
+    }
+}
+
+/// `my_jira_cli serve --addr 127.0.0.1:8080` starts the read-only admin
+/// HTTP API instead of the interactive TUI.
+fn run_serve(args: &[String]) {
+    let addr = args
+        .iter()
+        .position(|arg| arg == "--addr")
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+        .unwrap_or("127.0.0.1:8080");
+
+    let db = Rc::new(build_database());
+    if let Err(error) = api::serve(addr, db) {
+        eprintln!("admin API error: {}", error);
+        std::process::exit(1);
+    }
+}
+
 fn main() {
-    let db = Rc::new(JiraDatabase::new("./data/database.json"));
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("serve") {
+        run_serve(&args[2..]);
+        return;
+    }
+
+    let db = Rc::new(build_database());
     let mut navigator = Navigator::new(Rc::clone(&db));
-    
+
     loop {
         clearscreen::clear().unwrap();
 
@@ -1,3 +1,8 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Status {
     Open,
     InProgress,
@@ -5,10 +10,12 @@ pub enum Status {
     Closed
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Epic {
   pub name: String,
   pub description: String,
-  pub status: Status
+  pub status: Status,
+  pub stories: Vec<u32>
 }
 
 impl Epic {
@@ -16,11 +23,13 @@ impl Epic {
     Self {
       name,
       description,
-      status: Status::Open
+      status: Status::Open,
+      stories: vec![]
     }
   }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Story {
   pub name: String,
   pub description: String,
@@ -35,4 +44,13 @@ impl Story {
       status: Status::Open
     }
   }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DbState {
+  #[serde(default)]
+  pub schema_version: u32,
+  pub last_item_id: u32,
+  pub epics: HashMap<u32, Epic>,
+  pub stories: HashMap<u32, Story>
+}
@@ -0,0 +1,151 @@
+use std::rc::Rc;
+
+use anyhow::Result;
+
+use crate::db::JiraDatabase;
+use crate::models::Status;
+use crate::ui::pages_helpers::get_column_string;
+use crate::ui::prompts::Prompts;
+
+/// A user-triggered mutation, produced by a page's `handle_input` and
+/// carried out by the `Navigator` against the shared `JiraDatabase`.
+pub enum Action {
+  CreateEpic { name: String, description: String },
+  DeleteEpic { epic_id: u32 },
+  UpdateEpicStatus { epic_id: u32, status: Status },
+  CreateStory { epic_id: u32, name: String, description: String },
+  DeleteStory { epic_id: u32, story_id: u32 },
+  UpdateStoryStatus { story_id: u32, status: Status },
+  Undo,
+  Redo,
+  Exit,
+}
+
+/// Something `Navigator`'s page stack can draw and read input from. The
+/// only page this module ships is `EpicList`; there is no `EpicDetail` or
+/// `StoryDetail` page anywhere in the tree yet, so nothing currently routes
+/// to `Action::CreateStory`/`DeleteStory`/`UpdateStoryStatus` even though
+/// `JiraDatabase` and the undo/redo journal fully support them. That's a
+/// known gap for whoever wires up story-level navigation next, not
+/// something already handled elsewhere.
+pub trait Page {
+  fn draw_page(&self) -> Result<()>;
+  fn handle_input(&self, input: &str) -> Result<Option<Action>>;
+}
+
+/// The board's entry page: a flat list of epics, plus undo/redo over the
+/// whole board.
+struct EpicList {
+  db: Rc<JiraDatabase>,
+  prompts: Prompts,
+}
+
+impl Page for EpicList {
+  fn draw_page(&self) -> Result<()> {
+    let state = self.db.read()?;
+
+    println!("----------------------------- EPICS -----------------------------");
+    println!("     id     |               name               |      status      ");
+
+    let mut epic_ids: Vec<u32> = state.epics.keys().copied().collect();
+    epic_ids.sort();
+
+    for id in epic_ids {
+      let epic = &state.epics[&id];
+      println!(
+        "{} | {} | {}",
+        get_column_string(&id.to_string(), 11),
+        get_column_string(&epic.name, 32),
+        get_column_string(&format!("{:?}", epic.status), 17)
+      );
+    }
+
+    println!();
+    println!("[c] create epic  [d:<id>] delete epic  [p:<id>] update status  [u] undo  [r] redo  [q] quit");
+
+    Ok(())
+  }
+
+  fn handle_input(&self, input: &str) -> Result<Option<Action>> {
+    match input.trim() {
+      "q" => Ok(Some(Action::Exit)),
+      "u" => Ok(Some(Action::Undo)),
+      "r" => Ok(Some(Action::Redo)),
+      "c" => {
+        let epic = (self.prompts.create_epic)();
+        Ok(Some(Action::CreateEpic { name: epic.name, description: epic.description }))
+      }
+      input => {
+        if let Some(id) = input.strip_prefix("d:") {
+          let epic_id: u32 = id.parse()?;
+          return Ok((self.prompts.delete_epic)().then_some(Action::DeleteEpic { epic_id }));
+        }
+
+        if let Some(id) = input.strip_prefix("p:") {
+          let epic_id: u32 = id.parse()?;
+          return Ok((self.prompts.update_status)().map(|status| Action::UpdateEpicStatus { epic_id, status }));
+        }
+
+        Ok(None)
+      }
+    }
+  }
+}
+
+/// Dispatches `Action`s to the database and owns the page stack the main
+/// loop draws from. Nothing currently pushes a drill-down page onto `pages`
+/// (see the gap noted on `Page`); `Undo`/`Redo` don't touch the stack at
+/// all, since they operate on the board rather than on navigation.
+pub struct Navigator {
+  pages: Vec<Box<dyn Page>>,
+  db: Rc<JiraDatabase>,
+}
+
+impl Navigator {
+  pub fn new(db: Rc<JiraDatabase>) -> Self {
+    let home: Box<dyn Page> = Box::new(EpicList { db: Rc::clone(&db), prompts: Prompts::new() });
+
+    Self { pages: vec![home], db }
+  }
+
+  /// The page the main loop should currently draw and read input from, or
+  /// `None` once the stack has been emptied (e.g. by `Action::Exit`), which
+  /// tells the main loop to stop.
+  pub fn get_current_page(&mut self) -> Option<&mut Box<dyn Page>> {
+    self.pages.last_mut()
+  }
+
+  pub fn handle_action(&mut self, action: Action) -> Result<()> {
+    match action {
+      Action::CreateEpic { name, description } => {
+        self.db.create_epic(crate::models::Epic::new(name, description))?;
+      }
+      Action::DeleteEpic { epic_id } => {
+        self.db.delete_epic(epic_id)?;
+      }
+      Action::UpdateEpicStatus { epic_id, status } => {
+        self.db.update_epic_status(epic_id, status)?;
+      }
+      Action::CreateStory { epic_id, name, description } => {
+        self.db.create_story(crate::models::Story::new(name, description), epic_id)?;
+      }
+      Action::DeleteStory { epic_id, story_id } => {
+        self.db.delete_story(epic_id, story_id)?;
+      }
+      Action::UpdateStoryStatus { story_id, status } => {
+        self.db.update_story_status(story_id, status)?;
+      }
+      Action::Undo => {
+        self.db.undo()?;
+      }
+      Action::Redo => {
+        self.db.redo()?;
+      }
+      Action::Exit => {
+        self.pages.pop();
+      }
+    }
+
+    Ok(())
+  }
+}